@@ -0,0 +1,96 @@
+//! Batch MSD analysis over a directory of osu! beatmaps
+//!
+//! Borrows the library-walking approach of osu! song exporters: recurse
+//! through a `Songs`/pack root, run the existing [`OsuCalcExt`] pipeline on
+//! every `.osu` file found, and collect a result per file instead of
+//! aborting the whole run on the first bad chart.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::MinaCalcResult;
+use crate::osu::OsuCalcExt;
+use crate::{wrapper::AllRates, Calc};
+
+/// Options controlling a directory batch scan
+#[derive(Debug, Clone, Default)]
+pub struct BatchOptions {
+    /// Caps the number of worker threads used when the `rayon` feature is
+    /// enabled. `None` lets rayon pick based on available parallelism; has
+    /// no effect without the feature.
+    pub max_threads: Option<usize>,
+}
+
+/// The outcome of scoring a single file discovered under a batch root
+pub struct BatchEntry {
+    pub path: PathBuf,
+    pub result: MinaCalcResult<AllRates>,
+}
+
+impl Calc {
+    /// Recursively scores every `.osu` beatmap under `root`, running the
+    /// existing `security_check` + `to_notes_merged` + `calc_msd` pipeline
+    /// on each one. One bad file produces an `Err` entry rather than
+    /// aborting the scan.
+    pub fn calculate_directory(&self, root: &Path, opts: BatchOptions) -> Vec<BatchEntry> {
+        let paths = discover_osu_files(root);
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            // The FFI handle isn't obviously shareable across threads, so
+            // each worker gets its own calculator rather than reusing `self`
+            let score = |path: PathBuf| {
+                let result = Calc::new().and_then(|calc| calc.calculate_msd_from_osu_file(path.clone()));
+                BatchEntry { path, result }
+            };
+
+            return match opts.max_threads {
+                Some(threads) => match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+                    Ok(pool) => pool.install(|| paths.into_par_iter().map(score).collect()),
+                    // Couldn't honor the requested cap (e.g. the OS refused to
+                    // spawn that many threads) - fall back to rayon's global
+                    // pool rather than panicking over a batch scan
+                    Err(_) => paths.into_par_iter().map(score).collect(),
+                },
+                None => paths.into_par_iter().map(score).collect(),
+            };
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            let _ = opts;
+            paths
+                .into_iter()
+                .map(|path| {
+                    let result = self.calculate_msd_from_osu_file(path.clone());
+                    BatchEntry { path, result }
+                })
+                .collect()
+        }
+    }
+}
+
+/// Recursively walks `root`, returning every file with a `.osu` extension
+fn discover_osu_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().and_then(|ext| ext.to_str()) == Some("osu") {
+                files.push(path);
+            }
+        }
+    }
+
+    files
+}
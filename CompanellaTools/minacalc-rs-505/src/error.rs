@@ -22,6 +22,23 @@ pub enum MinaCalcError {
     InternalError(String),
     /// Osu! related error
     OsuError(OsuError),
+    /// StepMania (.sm/.ssc) related error
+    #[cfg(feature = "stepmania")]
+    SmError(SmError),
+}
+
+/// Custom error types for StepMania (.sm/.ssc) chart operations
+#[cfg(feature = "stepmania")]
+#[derive(Debug)]
+pub enum SmError {
+    /// Failed to read or parse the chart file
+    ParseFailed(String),
+    /// No `#NOTES` sections found in the file
+    NoNotesSections,
+    /// Steps type MinaCalc can't rate (only `dance-single` is supported)
+    UnsupportedStepsType(String),
+    /// Failed to convert a chart's rows to notes
+    NoteConversion(String),
 }
 
 /// Custom error types for osu! beatmap operations
@@ -55,6 +72,22 @@ impl fmt::Display for MinaCalcError {
             MinaCalcError::MemoryAllocationFailed => write!(f, "Memory allocation failed"),
             MinaCalcError::InternalError(msg) => write!(f, "Internal error: {}", msg),
             MinaCalcError::OsuError(osu_err) => write!(f, "Osu! error: {}", osu_err),
+            #[cfg(feature = "stepmania")]
+            MinaCalcError::SmError(sm_err) => write!(f, "StepMania error: {}", sm_err),
+        }
+    }
+}
+
+#[cfg(feature = "stepmania")]
+impl fmt::Display for SmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SmError::ParseFailed(msg) => write!(f, "Failed to parse chart: {}", msg),
+            SmError::NoNotesSections => write!(f, "No #NOTES sections found in file"),
+            SmError::UnsupportedStepsType(steps_type) => {
+                write!(f, "Unsupported steps type: {} (only dance-single is supported)", steps_type)
+            }
+            SmError::NoteConversion(msg) => write!(f, "Note conversion failed: {}", msg),
         }
     }
 }
@@ -76,6 +109,9 @@ impl fmt::Display for OsuError {
 impl Error for MinaCalcError {}
 impl Error for OsuError {}
 
+#[cfg(feature = "stepmania")]
+impl Error for SmError {}
+
 // Conversion from OsuError to MinaCalcError
 impl From<OsuError> for MinaCalcError {
     fn from(osu_err: OsuError) -> Self {
@@ -83,6 +119,16 @@ impl From<OsuError> for MinaCalcError {
     }
 }
 
+// Conversion from SmError to MinaCalcError
+#[cfg(feature = "stepmania")]
+impl From<SmError> for MinaCalcError {
+    fn from(sm_err: SmError) -> Self {
+        MinaCalcError::SmError(sm_err)
+    }
+}
+
 // Type alias for common result types
 pub type MinaCalcResult<T> = Result<T, MinaCalcError>;
 pub type OsuResult<T> = Result<T, OsuError>;
+#[cfg(feature = "stepmania")]
+pub type SmResult<T> = Result<T, SmError>;
@@ -7,6 +7,8 @@
 //! - `hashmap` (default): Provides HashMap conversion for MSD results
 //! - `thread`: Provides thread-safe calculator pool
 //! - `osu`: Provides osu! beatmap parsing and calculation
+//! - `rayon`: Parallelizes `Calc::calculate_directory` over a worker pool
+//! - `stepmania`: Provides StepMania (.sm/.ssc) chart parsing and calculation
 
 mod wrapper;
 mod error;
@@ -28,6 +30,12 @@ pub mod thread;
 #[cfg(feature = "osu")]
 pub mod osu;
 
+#[cfg(feature = "osu")]
+pub mod batch;
+
+#[cfg(feature = "stepmania")]
+pub mod sm;
+
 #[cfg(feature = "utils")]
 pub mod utils;
 
@@ -41,5 +49,11 @@ pub use thread::*;
 #[cfg(feature = "osu")]
 pub use osu::*;
 
+#[cfg(feature = "osu")]
+pub use batch::*;
+
+#[cfg(feature = "stepmania")]
+pub use sm::*;
+
 #[cfg(feature = "utils")]
 pub use utils::*;
@@ -8,61 +8,90 @@ use std::collections::HashMap;
 use crate::{Calc, Note, wrapper::AllRates};
 use crate::error::{OsuError, OsuResult, MinaCalcResult};
 
+/// Highest score-goal we'll actually hand to MinaCalc; the SSR curve
+/// diverges as the goal approaches 100%, so anything above this is clamped
+const MAX_SCORE_GOAL: f32 = 99.99;
+
 /// Extension trait for Calc to handle osu! beatmap operations
 pub trait OsuCalcExt {
-    /// Converts X position of a note to bitflag for 4K
-    fn get_columns(x: f32) -> OsuResult<u32>;
-    
+    /// Converts an osu!mania X position to a column bitflag for the given
+    /// keycount (`circle_size`)
+    fn get_columns(x: f32, keycount: u32) -> OsuResult<u32>;
+
     /// Converts a HitObject to Note for MinaCalc
-    fn hit_object_to_note(hit_object: HitObject) -> OsuResult<Note>;
-    
+    fn hit_object_to_note(hit_object: HitObject, keycount: u32) -> OsuResult<Note>;
+
     /// Converts beatmap to notes with automatic merging at same time
-    fn to_notes_merged(beatmap: &Beatmap) -> OsuResult<Vec<Note>>;
-    
+    fn to_notes_merged(beatmap: &Beatmap, keycount: u32) -> OsuResult<Vec<Note>>;
+
     /// Security check for beatmap validation
     fn security_check(beatmap: &Beatmap) -> OsuResult<()>;
-    
+
     /// Calculates MSD from osu! file path
     fn calculate_msd_from_osu_file(&self, path: PathBuf) -> MinaCalcResult<AllRates>;
-    
+
     /// Calculates MSD from osu! string
     fn calculate_msd_from_string(&self, string: String) -> MinaCalcResult<AllRates>;
 
     /// Calculates MSD for an arbitrary rate by scaling note times
     /// This allows any rate > 0, not just the predefined 0.7-2.0 rates
     fn calculate_msd_at_rate(&self, path: PathBuf, rate: f32) -> MinaCalcResult<crate::SkillsetScores>;
-    
-    /// Validates a collection of notes
-    fn validate_notes(notes: &[Note]) -> OsuResult<()>;
+
+    /// Calculates the SSR (score-goal difficulty) a specific accuracy goal
+    /// rates as, rather than the chart's fixed MSD ceiling.
+    ///
+    /// `goal` is a wife% accuracy fraction in `(0.0, 1.0]` (e.g. `0.93` for
+    /// 93%). Values approaching `1.0` make the SSR curve diverge, so `goal`
+    /// is clamped to a safe maximum before being threaded into the FFI call.
+    fn calculate_ssr_at_goal(
+        &self,
+        notes: &[Note],
+        keycount: u32,
+        rate: f32,
+        goal: f32,
+    ) -> MinaCalcResult<crate::SkillsetScores>;
+
+    /// Calculates the SSR for a specific accuracy goal from an osu! file path
+    fn calculate_ssr_from_osu_file(
+        &self,
+        path: PathBuf,
+        rate: f32,
+        goal: f32,
+    ) -> MinaCalcResult<crate::SkillsetScores>;
+
+    /// Validates a collection of notes against a keycount's column range
+    fn validate_notes(notes: &[Note], keycount: u32) -> OsuResult<()>;
 }
 
 impl OsuCalcExt for Calc {
-    /// Converts X position of a note to bitflag for 4K
-    fn get_columns(x: f32) -> OsuResult<u32> {
-        match x {
-            64.0 => Ok(1),  // bit flag 0b0001
-            192.0 => Ok(2), // bit flag 0b0010
-            320.0 => Ok(4), // bit flag 0b0100
-            448.0 => Ok(8), // bit flag 0b1000
-            _ => Err(OsuError::UnsupportedColumn(x))
+    /// Converts an osu!mania X position to a column bitflag for the given
+    /// keycount (`circle_size`)
+    fn get_columns(x: f32, keycount: u32) -> OsuResult<u32> {
+        if keycount == 0 || x < 0.0 || x > 512.0 {
+            return Err(OsuError::UnsupportedColumn(x));
         }
+
+        let column_index = ((x * keycount as f32) / 512.0).floor() as u32;
+        let column_index = column_index.min(keycount - 1);
+
+        Ok(1 << column_index)
     }
 
     /// Converts a HitObject to Note for MinaCalc
-    fn hit_object_to_note(hit_object: HitObject) -> OsuResult<Note> {
+    fn hit_object_to_note(hit_object: HitObject, keycount: u32) -> OsuResult<Note> {
         let time = (hit_object.start_time as f32) / 1000.0; // Convert ms to seconds
-        
+
         if time < 0.0 {
             return Err(OsuError::HitObjectConversion("Negative time not allowed".to_string()));
         }
-        
+
         match hit_object.kind {
             HitObjectKind::Circle(hit_object) => {
-                let notes = Self::get_columns(hit_object.pos.x)?;
+                let notes = Self::get_columns(hit_object.pos.x, keycount)?;
                 Ok(Note{notes, row_time: time})
             },
             HitObjectKind::Hold(hit_object) => {
-                let notes = Self::get_columns(hit_object.pos_x)?;
+                let notes = Self::get_columns(hit_object.pos_x, keycount)?;
                 Ok(Note{notes, row_time: time})
             },
             _ => Err(OsuError::UnsupportedHitObjectKind(format!("{:#?}", hit_object.kind)))
@@ -70,15 +99,15 @@ impl OsuCalcExt for Calc {
     }
 
     /// Converts beatmap to notes with automatic merging at same time
-    fn to_notes_merged(beatmap: &Beatmap) -> OsuResult<Vec<Note>> {
+    fn to_notes_merged(beatmap: &Beatmap, keycount: u32) -> OsuResult<Vec<Note>> {
         let mut time_notes: HashMap<i32, u32> = HashMap::new();
-        
+
         // Convert and merge in one pass
         for hit_object in &beatmap.hit_objects {
-            if let Ok(note) = Self::hit_object_to_note(hit_object.clone()) {
+            if let Ok(note) = Self::hit_object_to_note(hit_object.clone(), keycount) {
                 // Convert time to integer for HashMap key (multiply by 1000 to preserve precision)
                 let time_key = (note.row_time * 1000.0) as i32;
-                
+
                 // Merge bitflags for same time using OR operation
                 time_notes.entry(time_key)
                     .and_modify(|existing_notes| *existing_notes |= note.notes)
@@ -87,26 +116,26 @@ impl OsuCalcExt for Calc {
                 return Err(OsuError::HitObjectConversion("Failed to convert hit object".to_string()));
             }
         }
-        
+
         if time_notes.is_empty() {
             return Err(OsuError::HitObjectConversion("No valid notes found in beatmap".to_string()));
         }
-        
+
         // Convert HashMap back to sorted Vec<Note>
         let mut notes: Vec<Note> = time_notes
             .into_iter()
-            .map(|(time_key, notes)| Note { 
-                notes, 
-                row_time: (time_key as f32) / 1000.0 
+            .map(|(time_key, notes)| Note {
+                notes,
+                row_time: (time_key as f32) / 1000.0
             })
             .collect();
-        
+
         // Sort by time
         notes.sort_by(|a, b| a.row_time.partial_cmp(&b.row_time).unwrap());
-        
+
         // Validate all notes
-        Self::validate_notes(&notes)?;
-        
+        Self::validate_notes(&notes, keycount)?;
+
         Ok(notes)
     }
 
@@ -114,36 +143,41 @@ impl OsuCalcExt for Calc {
         if beatmap.mode != GameMode::Mania {
             return Err(OsuError::UnsupportedGameMode(format!("{:?}", beatmap.mode)));
         }
-        if beatmap.circle_size != 4.0 {
+        if beatmap.circle_size != 4.0 && beatmap.circle_size != 6.0 && beatmap.circle_size != 7.0 {
             return Err(OsuError::UnsupportedKeyCount(beatmap.circle_size));
         }
         Ok(())
     }
-    
-    fn validate_notes(notes: &[Note]) -> OsuResult<()> {
+
+    fn validate_notes(notes: &[Note], keycount: u32) -> OsuResult<()> {
         if notes.is_empty() {
             return Err(OsuError::HitObjectConversion("No notes to validate".to_string()));
         }
-        
+
+        let max_columns = 1u32 << keycount;
+
         for (i, note) in notes.iter().enumerate() {
             if note.notes == 0 {
                 return Err(OsuError::HitObjectConversion(format!("Note {} has no columns", i)));
             }
-            if note.notes > 0b1111 {
-                return Err(OsuError::HitObjectConversion(format!("Note {} exceeds 4K limit", i)));
+            if note.notes >= max_columns {
+                return Err(OsuError::HitObjectConversion(format!(
+                    "Note {} exceeds {}K limit",
+                    i, keycount
+                )));
             }
             if note.row_time < 0.0 {
                 return Err(OsuError::HitObjectConversion(format!("Note {} has negative time", i)));
             }
         }
-        
+
         // Check for duplicate times
         for i in 1..notes.len() {
             if notes[i].row_time == notes[i-1].row_time {
                 return Err(OsuError::HitObjectConversion(format!("Duplicate time at index {}", i)));
             }
         }
-        
+
         Ok(())
     }
 
@@ -152,9 +186,10 @@ impl OsuCalcExt for Calc {
             .map_err(|e| OsuError::ParseFailed(format!("Failed to parse {}: {}", path.display(), e)))?;
 
         Self::security_check(&beatmap)?;
-        let notes = Self::to_notes_merged(&beatmap)?;
+        let keycount = beatmap.circle_size as u32;
+        let notes = Self::to_notes_merged(&beatmap, keycount)?;
 
-        let msd = self.calc_msd(&notes)?;
+        let msd = self.calc_msd_with_keycount(&notes, keycount)?;
 
         Ok(msd)
     }
@@ -165,15 +200,16 @@ impl OsuCalcExt for Calc {
             .map_err(|e| OsuError::ParseFailed(format!("Failed to parse string: {}", e)))?;
 
         Self::security_check(&beatmap)?;
-        let notes = Self::to_notes_merged(&beatmap)?;
+        let keycount = beatmap.circle_size as u32;
+        let notes = Self::to_notes_merged(&beatmap, keycount)?;
 
-        let msd = self.calc_msd(&notes)?;
+        let msd = self.calc_msd_with_keycount(&notes, keycount)?;
         Ok(msd)
     }
-    
+
     fn calculate_msd_at_rate(&self, path: PathBuf, rate: f32) -> MinaCalcResult<crate::SkillsetScores> {
         use crate::error::MinaCalcError;
-        
+
         if rate <= 0.0 {
             return Err(MinaCalcError::InvalidMusicRate(rate));
         }
@@ -182,7 +218,8 @@ impl OsuCalcExt for Calc {
             .map_err(|e| OsuError::ParseFailed(format!("Failed to parse {}: {}", path.display(), e)))?;
 
         Self::security_check(&beatmap)?;
-        let notes = Self::to_notes_merged(&beatmap)?;
+        let keycount = beatmap.circle_size as u32;
+        let notes = Self::to_notes_merged(&beatmap, keycount)?;
 
         // Scale note times by 1/rate to simulate playing at different speed
         // Higher rate = faster = shorter times between notes = harder
@@ -196,10 +233,128 @@ impl OsuCalcExt for Calc {
             .collect();
 
         // Calculate MSD on scaled notes
-        let all_rates = self.calc_msd(&scaled_notes)?;
+        let all_rates = self.calc_msd_with_keycount(&scaled_notes, keycount)?;
 
         // Return the 1.0x rate result (index 3 in the array)
         // Since we already scaled the notes, this gives us the MSD at the requested rate
         Ok(all_rates.msds[3])
     }
+
+    fn calculate_ssr_at_goal(
+        &self,
+        notes: &[Note],
+        keycount: u32,
+        rate: f32,
+        goal: f32,
+    ) -> MinaCalcResult<crate::SkillsetScores> {
+        use crate::error::MinaCalcError;
+
+        if rate <= 0.0 {
+            return Err(MinaCalcError::InvalidMusicRate(rate));
+        }
+        if goal <= 0.0 || goal > 1.0 {
+            return Err(MinaCalcError::InvalidScoreGoal(goal));
+        }
+
+        // MinaCalc's score_goal is a 0-100 percentage, and the SSR curve
+        // diverges as it approaches 100%, so clamp before converting
+        let score_goal = (goal * 100.0).min(MAX_SCORE_GOAL);
+
+        self.calc_ssr_with_keycount(notes, rate, score_goal, keycount)
+    }
+
+    fn calculate_ssr_from_osu_file(
+        &self,
+        path: PathBuf,
+        rate: f32,
+        goal: f32,
+    ) -> MinaCalcResult<crate::SkillsetScores> {
+        let beatmap: Beatmap = rosu_map::from_path(&path)
+            .map_err(|e| OsuError::ParseFailed(format!("Failed to parse {}: {}", path.display(), e)))?;
+
+        Self::security_check(&beatmap)?;
+        let keycount = beatmap.circle_size as u32;
+        let notes = Self::to_notes_merged(&beatmap, keycount)?;
+
+        self.calculate_ssr_at_goal(&notes, keycount, rate, goal)
+    }
+}
+
+// Helper extension for Calc to support a keycount parameter, since this
+// crate's base `calc_msd`/`calc_ssr` hardcode 4K.
+//
+// `minacalc-rs` has its own `calc_msd_with_keycount`/`calc_ssr_with_keycount`
+// pair (in `rox::mod`) that looks identical to this one, but it isn't
+// reachable from here: it's threaded through that crate's own `Calc::handle`
+// and bindgen-generated `NoteInfo`/`AllRates`/`SkillsetScores`, which bind a
+// different build of the native MinaCalc library than this crate's. Calling
+// through to it would run the wrong native calculator, so the two copies stay
+// separate until something unifies the native libraries themselves.
+impl Calc {
+    /// Calculates MSD with configurable keycount
+    pub fn calc_msd_with_keycount(&self, notes: &[Note], keycount: u32) -> MinaCalcResult<AllRates> {
+        use crate::error::MinaCalcError;
+
+        if notes.is_empty() {
+            return Err(MinaCalcError::NoNotesProvided);
+        }
+
+        for note in notes {
+            note.validate()?;
+        }
+
+        let note_infos: Vec<crate::NoteInfo> = notes.iter().map(|&note| note.into()).collect();
+
+        let result = unsafe {
+            crate::calc_msd(self.handle, note_infos.as_ptr(), note_infos.len(), keycount)
+        };
+
+        let msd: AllRates = result.into();
+        msd.validate()?;
+        Ok(msd)
+    }
+
+    /// Calculates SSR with configurable keycount, taking `score_goal` as a
+    /// 0-100 percentage (see [`OsuCalcExt::calculate_ssr_at_goal`] for the
+    /// 0.0-1.0 fraction used at the crate boundary)
+    pub fn calc_ssr_with_keycount(
+        &self,
+        notes: &[Note],
+        music_rate: f32,
+        score_goal: f32,
+        keycount: u32,
+    ) -> MinaCalcResult<crate::SkillsetScores> {
+        use crate::error::MinaCalcError;
+
+        if notes.is_empty() {
+            return Err(MinaCalcError::NoNotesProvided);
+        }
+        if music_rate <= 0.0 {
+            return Err(MinaCalcError::InvalidMusicRate(music_rate));
+        }
+        if score_goal <= 0.0 || score_goal > 100.0 {
+            return Err(MinaCalcError::InvalidScoreGoal(score_goal));
+        }
+
+        for note in notes {
+            note.validate()?;
+        }
+
+        let mut note_infos: Vec<crate::NoteInfo> = notes.iter().map(|&note| note.into()).collect();
+
+        let result = unsafe {
+            crate::calc_ssr(
+                self.handle,
+                note_infos.as_mut_ptr(),
+                note_infos.len(),
+                music_rate,
+                score_goal,
+                keycount,
+            )
+        };
+
+        let scores: crate::SkillsetScores = result.into();
+        scores.validate()?;
+        Ok(scores)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,360 @@
+//! StepMania (.sm/.ssc) chart parsing and per-difficulty MSD
+//!
+//! Unlike the osu! path, a single `.sm`/`.ssc` file packs several charts
+//! (Beginner through Challenge, plus Edits) under one song. `.sm` keys each
+//! chart off one colon-delimited `#NOTES:stepstype:desc:difficulty:meter:
+//! radar:data;` header, while `.ssc` instead wraps each chart in its own
+//! `#NOTEDATA:;` block with `#STEPSTYPE`/`#DIFFICULTY`/`#METER`/`#NOTES`
+//! as sibling tags; this module reads both layouts, converts each chart's
+//! rows to `Vec<Note>`, and scores them independently.
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::{MinaCalcResult, SmError, SmResult};
+use crate::{wrapper::AllRates, Calc, Note};
+
+/// Which difficulty slot a StepMania chart was authored under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChartSlot {
+    Beginner,
+    Easy,
+    Medium,
+    Hard,
+    Challenge,
+    Edit,
+}
+
+impl ChartSlot {
+    fn from_difficulty(difficulty: &str) -> Option<ChartSlot> {
+        match difficulty.trim().to_ascii_lowercase().as_str() {
+            "beginner" => Some(ChartSlot::Beginner),
+            "easy" | "basic" => Some(ChartSlot::Easy),
+            "medium" | "another" | "trick" => Some(ChartSlot::Medium),
+            "hard" | "maniac" | "ssr" => Some(ChartSlot::Hard),
+            "challenge" | "smaniac" | "expert" => Some(ChartSlot::Challenge),
+            "edit" => Some(ChartSlot::Edit),
+            _ => None,
+        }
+    }
+}
+
+/// One parsed `#NOTES` section, before conversion to MinaCalc notes
+struct RawChart {
+    steps_type: String,
+    slot: ChartSlot,
+    meter: u32,
+    measures: Vec<Vec<String>>,
+}
+
+/// A BPM change, `(beat, beats_per_minute)`, sorted ascending by beat
+type BpmSegment = (f64, f64);
+
+/// A stop, `(beat, duration_seconds)`
+type Stop = (f64, f64);
+
+/// Extension trait for Calc to handle StepMania `.sm`/`.ssc` chart operations
+pub trait StepmaniaCalcExt {
+    /// Parses every `#NOTES` section in a `.sm`/`.ssc` file's contents into
+    /// MinaCalc notes, keyed by difficulty slot and meter
+    fn sm_file_to_notes(contents: &str) -> SmResult<Vec<(ChartSlot, u32, Vec<Note>)>>;
+
+    /// Calculates MSD for every chart in a `.sm`/`.ssc` file
+    fn calculate_msd_from_sm_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> MinaCalcResult<Vec<(ChartSlot, u32, AllRates)>>;
+}
+
+impl StepmaniaCalcExt for Calc {
+    fn sm_file_to_notes(contents: &str) -> SmResult<Vec<(ChartSlot, u32, Vec<Note>)>> {
+        let offset = parse_tag_f64(contents, "#OFFSET").unwrap_or(0.0);
+        let bpms = parse_bpms(contents)?;
+        let stops = parse_stops(contents);
+
+        let mut raw_charts = parse_notes_sections(contents)?;
+        raw_charts.extend(parse_notedata_sections(contents));
+        if raw_charts.is_empty() {
+            return Err(SmError::NoNotesSections);
+        }
+
+        let mut charts = Vec::with_capacity(raw_charts.len());
+        for chart in raw_charts {
+            if chart.steps_type != "dance-single" {
+                continue;
+            }
+
+            let notes = measures_to_notes(&chart.measures, offset, &bpms, &stops)?;
+            charts.push((chart.slot, chart.meter, notes));
+        }
+
+        if charts.is_empty() {
+            return Err(SmError::UnsupportedStepsType(
+                "no dance-single charts found".to_string(),
+            ));
+        }
+
+        Ok(charts)
+    }
+
+    fn calculate_msd_from_sm_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> MinaCalcResult<Vec<(ChartSlot, u32, AllRates)>> {
+        let contents = fs::read_to_string(path.as_ref())
+            .map_err(|e| SmError::ParseFailed(format!("Failed to read {}: {}", path.as_ref().display(), e)))?;
+
+        let charts = Self::sm_file_to_notes(&contents)?;
+
+        charts
+            .into_iter()
+            .map(|(slot, meter, notes)| {
+                let msd = self.calc_msd(&notes)?;
+                Ok((slot, meter, msd))
+            })
+            .collect()
+    }
+}
+
+/// Finds every `#NOTES:` section and parses its header fields + measures
+fn parse_notes_sections(contents: &str) -> SmResult<Vec<RawChart>> {
+    let mut charts = Vec::new();
+    let mut rest = contents;
+
+    while let Some(start) = rest.find("#NOTES:") {
+        let body_start = start + "#NOTES:".len();
+        let Some(end_offset) = rest[body_start..].find(';') else {
+            break;
+        };
+        let body = &rest[body_start..body_start + end_offset];
+
+        // Header: stepstype:description:difficulty:meter:radar values:
+        let mut fields = body.splitn(6, ':');
+        let steps_type = fields.next().unwrap_or_default().trim().to_string();
+        let _description = fields.next();
+        let difficulty = fields.next().unwrap_or_default();
+        let meter = fields.next().unwrap_or_default();
+        let _radar = fields.next();
+        let note_data = fields.next().unwrap_or_default();
+
+        let slot = ChartSlot::from_difficulty(difficulty).unwrap_or(ChartSlot::Edit);
+        let meter: u32 = meter.trim().parse().unwrap_or(0);
+        let measures = split_measures(note_data);
+
+        charts.push(RawChart {
+            steps_type,
+            slot,
+            meter,
+            measures,
+        });
+
+        rest = &rest[body_start + end_offset + 1..];
+    }
+
+    Ok(charts)
+}
+
+/// Finds every `#NOTEDATA:;` block (the `.ssc` per-chart layout: step type,
+/// difficulty, meter, and note data live in sibling tags within the block
+/// rather than one colon-delimited `#NOTES:` header like `.sm`) and parses
+/// each into a `RawChart`
+fn parse_notedata_sections(contents: &str) -> Vec<RawChart> {
+    let mut charts = Vec::new();
+    let mut rest = contents;
+
+    while let Some(start) = rest.find("#NOTEDATA:") {
+        let block_start = start + "#NOTEDATA:".len();
+        let block_end = rest[block_start..]
+            .find("#NOTEDATA:")
+            .map(|offset| block_start + offset)
+            .unwrap_or(rest.len());
+        let block = &rest[block_start..block_end];
+
+        rest = &rest[block_end..];
+
+        let Some(note_data) = parse_tag_str(block, "#NOTES") else {
+            continue;
+        };
+        let steps_type = parse_tag_str(block, "#STEPSTYPE").unwrap_or_default().to_string();
+        let difficulty = parse_tag_str(block, "#DIFFICULTY").unwrap_or_default();
+        let meter: u32 = parse_tag_str(block, "#METER")
+            .and_then(|meter| meter.trim().parse().ok())
+            .unwrap_or(0);
+
+        charts.push(RawChart {
+            steps_type,
+            slot: ChartSlot::from_difficulty(difficulty).unwrap_or(ChartSlot::Edit),
+            meter,
+            measures: split_measures(note_data),
+        });
+    }
+
+    charts
+}
+
+/// Splits a `#NOTES` body's comma-separated measures into trimmed,
+/// comment-stripped rows
+fn split_measures(note_data: &str) -> Vec<Vec<String>> {
+    note_data
+        .split(',')
+        .map(|measure| {
+            measure
+                .lines()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty() && !line.starts_with("//"))
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+        })
+        .filter(|measure: &Vec<String>| !measure.is_empty())
+        .collect()
+}
+
+/// Reads a single `#TAG:value;`'s trimmed value
+fn parse_tag_str<'a>(contents: &'a str, tag: &str) -> Option<&'a str> {
+    let start = contents.find(tag)? + tag.len();
+    let rest = &contents[start..];
+    let start = rest.find(':')? + 1;
+    let end = rest[start..].find(';')?;
+    Some(rest[start..start + end].trim())
+}
+
+/// Reads a single numeric `#TAG:value;`
+fn parse_tag_f64(contents: &str, tag: &str) -> Option<f64> {
+    parse_tag_str(contents, tag)?.parse().ok()
+}
+
+/// Parses `#BPMS:beat=bpm,beat=bpm,...;` into sorted segments
+fn parse_bpms(contents: &str) -> SmResult<Vec<BpmSegment>> {
+    let Some(start) = contents.find("#BPMS:") else {
+        return Ok(vec![(0.0, 120.0)]);
+    };
+    let body_start = start + "#BPMS:".len();
+    let end = contents[body_start..]
+        .find(';')
+        .ok_or_else(|| SmError::ParseFailed("unterminated #BPMS tag".to_string()))?;
+    let body = &contents[body_start..body_start + end];
+
+    let mut segments: Vec<BpmSegment> = body
+        .split(',')
+        .filter_map(|pair| {
+            let (beat, bpm) = pair.split_once('=')?;
+            Some((beat.trim().parse().ok()?, bpm.trim().parse().ok()?))
+        })
+        .collect();
+
+    if segments.is_empty() {
+        segments.push((0.0, 120.0));
+    }
+    segments.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(segments)
+}
+
+/// Parses `#STOPS:beat=seconds,...;`
+fn parse_stops(contents: &str) -> Vec<Stop> {
+    let Some(start) = contents.find("#STOPS:") else {
+        return Vec::new();
+    };
+    let body_start = start + "#STOPS:".len();
+    let Some(end) = contents[body_start..].find(';') else {
+        return Vec::new();
+    };
+    let body = &contents[body_start..body_start + end];
+
+    let mut stops: Vec<Stop> = body
+        .split(',')
+        .filter_map(|pair| {
+            let (beat, duration) = pair.split_once('=')?;
+            Some((beat.trim().parse().ok()?, duration.trim().parse().ok()?))
+        })
+        .collect();
+
+    stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    stops
+}
+
+/// Converts a beat position to an absolute time in seconds, honoring
+/// `#OFFSET`, BPM changes, and stops
+fn beat_to_seconds(beat: f64, offset: f64, bpms: &[BpmSegment], stops: &[Stop]) -> f64 {
+    let mut time = -offset;
+    let mut prev_beat = 0.0;
+    let mut prev_bpm = bpms.first().map(|&(_, bpm)| bpm).unwrap_or(120.0);
+
+    for &(seg_beat, bpm) in bpms {
+        if seg_beat >= beat {
+            break;
+        }
+        time += (seg_beat - prev_beat) * 60.0 / prev_bpm;
+        prev_beat = seg_beat;
+        prev_bpm = bpm;
+    }
+    time += (beat - prev_beat) * 60.0 / prev_bpm;
+
+    for &(stop_beat, duration) in stops {
+        if stop_beat < beat {
+            time += duration;
+        }
+    }
+
+    time
+}
+
+/// Converts a chart's measures into timed `Note`s
+///
+/// Each measure is subdivided evenly into beats (a measure always spans 4
+/// beats); row characters `1`/`2`/`4` (tap, hold head, roll head) set the
+/// corresponding column bit, while `0`/`3` (empty, hold/roll tail) don't
+/// start a new tap for timing purposes. Rows before `t=0` (a positive
+/// `#OFFSET`'s lead-in) are dropped rather than failing the whole chart.
+fn measures_to_notes(
+    measures: &[Vec<String>],
+    offset: f64,
+    bpms: &[BpmSegment],
+    stops: &[Stop],
+) -> SmResult<Vec<Note>> {
+    let mut notes = Vec::new();
+
+    for (measure_index, rows) in measures.iter().enumerate() {
+        if rows.is_empty() {
+            continue;
+        }
+        let rows_per_measure = rows.len() as f64;
+
+        for (row_index, row) in rows.iter().enumerate() {
+            let mut columns = 0u32;
+            for (column, ch) in row.chars().enumerate() {
+                if column >= 4 {
+                    break;
+                }
+                if ch == '1' || ch == '2' || ch == '4' {
+                    columns |= 1 << column;
+                }
+            }
+
+            if columns == 0 {
+                continue;
+            }
+
+            let beat = measure_index as f64 * 4.0 + (row_index as f64 * 4.0 / rows_per_measure);
+            let row_time = beat_to_seconds(beat, offset, bpms, stops) as f32;
+
+            if row_time < 0.0 {
+                // A positive #OFFSET pushes early rows before t=0; that's a
+                // normal lead-in, not malformed data, so drop the row
+                // instead of aborting the whole chart
+                continue;
+            }
+
+            notes.push(Note {
+                notes: columns,
+                row_time,
+            });
+        }
+    }
+
+    if notes.is_empty() {
+        return Err(SmError::NoteConversion("chart has no tappable rows".to_string()));
+    }
+
+    notes.sort_by(|a, b| a.row_time.partial_cmp(&b.row_time).unwrap());
+    Ok(notes)
+}
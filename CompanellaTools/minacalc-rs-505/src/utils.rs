@@ -1,18 +1,46 @@
 use crate::{Ssr, wrapper::SkillsetScores};
 
+// `Skillset` has no dependency on this crate's own FFI types (it's just a
+// 7-variant label), so it's re-exported from `minacalc-rs` instead of
+// redefined here. That crate is the one `msd-calculator` actually links
+// against, so it's the canonical home for it; this crate only needed its
+// own copy of `get()` below, which *does* depend on this crate's own
+// `SkillsetScores` (a distinct bindgen type from minacalc-rs's).
+pub use minacalc_rs::Skillset;
+
+/// Extension trait reading a [`Skillset`] value out of this crate's own
+/// `SkillsetScores`, mirroring `Skillset::get` on the `minacalc-rs` side.
+pub trait SkillsetScoresExt {
+    fn get(&self, skillset: Skillset) -> f32;
+}
+
+impl SkillsetScoresExt for SkillsetScores {
+    fn get(&self, skillset: Skillset) -> f32 {
+        match skillset {
+            Skillset::Stream => self.stream,
+            Skillset::Jumpstream => self.jumpstream,
+            Skillset::Handstream => self.handstream,
+            Skillset::Stamina => self.stamina,
+            Skillset::Jackspeed => self.jackspeed,
+            Skillset::Chordjack => self.chordjack,
+            Skillset::Technical => self.technical,
+        }
+    }
+}
+
 /// Calculates the highest rated patterns from skillset scores
-/// 
+///
 /// # Arguments
 /// * `skillset` - The skillset scores to analyze
 /// * `number` - The number of top patterns to return
-/// 
+///
 /// # Returns
-/// A vector of pattern names sorted by rating (highest first)
-/// 
+/// A vector of skillsets sorted by rating (highest first)
+///
 /// # Example
 /// ```
 /// use minacalc_rs::{SkillsetScores, utils::calculate_highest_patterns};
-/// 
+///
 /// let skillset = SkillsetScores {
 ///     overall: 10.0,
 ///     stream: 8.0,
@@ -23,33 +51,30 @@ use crate::{Ssr, wrapper::SkillsetScores};
 ///     chordjack: 1.0,
 ///     technical: 3.0,
 /// };
-/// 
+///
 /// let top_patterns = calculate_highest_patterns(&skillset, 3);
-/// // Returns: ["jumpstream", "stream", "handstream"]
+/// // Returns: [Jumpstream, Stream, Handstream]
 /// ```
-pub fn calculate_highest_patterns(skillset: &SkillsetScores, number: i8) -> Vec<String> {
-    let patterns = vec![
-        ("stream", skillset.stream),
-        ("jumpstream", skillset.jumpstream),
-        ("handstream", skillset.handstream),
-        ("stamina", skillset.stamina),
-        ("jackspeed", skillset.jackspeed),
-        ("chordjack", skillset.chordjack),
-        ("technical", skillset.technical),
-    ];
-
-    // Trier par rating décroissant
-    let mut sorted_patterns: Vec<_> = patterns.into_iter().collect();
-    sorted_patterns.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+pub fn calculate_highest_patterns(skillset: &SkillsetScores, number: i8) -> Vec<Skillset> {
+    let mut ranked: Vec<Skillset> = Skillset::all().collect();
+    ranked.sort_by(|a, b| {
+        skillset
+            .get(*b)
+            .partial_cmp(&skillset.get(*a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    ranked.into_iter().take(number as usize).collect()
+}
 
-    // Prendre les N premiers patterns
-    let top_patterns: Vec<String> = sorted_patterns
+/// Calculates the highest rated patterns from skillset scores as pattern names
+///
+/// Thin back-compat shim over [`calculate_highest_patterns`] for callers that
+/// haven't migrated to [`Skillset`] yet.
+pub fn calculate_highest_patterns_str(skillset: &SkillsetScores, number: i8) -> Vec<String> {
+    calculate_highest_patterns(skillset, number)
         .into_iter()
-        .take(number as usize)
-        .map(|(pattern, _)| pattern.to_string())
-        .collect();
-
-    top_patterns
+        .map(|skillset| skillset.as_str().to_string())
+        .collect()
 }
 
 /// Calculates the highest rated patterns from Ssr scores (converts to SkillsetScores first)
@@ -62,5 +87,88 @@ pub fn calculate_highest_patterns(skillset: &SkillsetScores, number: i8) -> Vec<
 /// A vector of pattern names sorted by rating (highest first)
 pub fn calculate_highest_patterns_from_ssr(ssr: &Ssr, number: i8) -> Vec<String> {
     let skillset: SkillsetScores = (*ssr).into();
-    calculate_highest_patterns(&skillset, number)
+    calculate_highest_patterns_str(&skillset, number)
+}
+
+/// Etterna's overall-rating fudge factor, applied after aggregating the
+/// seven per-skillset ratings into a single number
+pub const OVERALL_FUDGE_FACTOR: f32 = 1.04;
+
+/// Complementary error function, approximated with the Numerical Recipes
+/// rational Chebyshev fit (fractional error below 1.2e-7). `std` has no
+/// `erfc`, and the Etterna rating bisection depends on it, so we carry our
+/// own approximation rather than pull in a dependency for one function.
+fn erfc(x: f32) -> f32 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let ans = t
+        * (-z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+            .exp();
+
+    if x >= 0.0 {
+        ans
+    } else {
+        2.0 - ans
+    }
+}
+
+/// Aggregates a single skillset's collection of per-score SSR values into one
+/// rating, matching Etterna's in-game bisection.
+///
+/// Returns `0.0` for an empty `ssrs` slice. Negative SSRs are clamped to
+/// `0.0` before aggregation.
+pub fn aggregate_skillset_rating(ssrs: &[f32]) -> f32 {
+    if ssrs.is_empty() {
+        return 0.0;
+    }
+
+    let ssrs: Vec<f32> = ssrs.iter().map(|&ssr| ssr.max(0.0)).collect();
+
+    let mut rating = 0.0f32;
+    let mut res = 10.24f32;
+
+    for _ in 0..11 {
+        loop {
+            rating += res;
+
+            let sum: f32 = ssrs
+                .iter()
+                .map(|&ssr| (2.0 / erfc(0.1 * (ssr - rating)) - 2.0).max(0.0))
+                .sum();
+
+            if 2f32.powf(rating * 0.1) < sum {
+                continue;
+            }
+            break;
+        }
+
+        rating -= res;
+        res /= 2.0;
+    }
+
+    rating
+}
+
+/// Computes a player's overall rating from their per-skillset ratings, by
+/// running the same bisection over the seven skillset values and applying
+/// Etterna's overall fudge factor.
+pub fn calculate_player_overall(per_skillset: &SkillsetScores) -> f32 {
+    let skillset_ratings = [
+        per_skillset.stream,
+        per_skillset.jumpstream,
+        per_skillset.handstream,
+        per_skillset.stamina,
+        per_skillset.jackspeed,
+        per_skillset.chordjack,
+        per_skillset.technical,
+    ];
+
+    aggregate_skillset_rating(&skillset_ratings) * OVERALL_FUDGE_FACTOR
 }
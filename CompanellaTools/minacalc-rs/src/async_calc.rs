@@ -0,0 +1,88 @@
+//! Async calculation API that offloads FFI work onto a blocking pool
+//!
+//! [`AsyncCalcExt`] mirrors [`RoxCalcExt`](crate::rox::RoxCalcExt), but each
+//! method returns a future instead of blocking the calling task. The actual
+//! `calc_msd`/`calc_ssr` FFI calls are synchronous and CPU-bound, so the
+//! work (decode, convert, call) runs on `spawn_blocking` against a [`Calc`]
+//! borrowed from the [`thread`](crate::thread) pool, with the result handed
+//! back through a oneshot. This lets a service scoring many charts
+//! concurrently keep its runtime responsive.
+
+use std::path::PathBuf;
+
+use rhythm_open_exchange::RoxChart;
+use tokio::sync::oneshot;
+
+use crate::error::{MinaCalcError, MinaCalcResult};
+use crate::rox::RoxCalcExt;
+use crate::thread::ThreadSafeCalcPool;
+use crate::{wrapper::AllRates, wrapper::SkillsetScores, Calc};
+
+/// Extension trait mirroring [`RoxCalcExt`](crate::rox::RoxCalcExt) with
+/// futures-returning methods
+pub trait AsyncCalcExt {
+    /// Calculates SSR (single rate) from any supported rhythm game file,
+    /// without blocking the calling task
+    async fn calculate_ssr_from_file_async(
+        path: PathBuf,
+        music_rate: f32,
+        score_goal: f32,
+        chart_rate: Option<f32>,
+    ) -> MinaCalcResult<SkillsetScores>;
+
+    /// Calculates MSD for all rates (0.7x to 2.0x) from any supported
+    /// rhythm game file, without blocking the calling task
+    async fn calculate_all_rates_from_file_async(path: PathBuf) -> MinaCalcResult<AllRates>;
+
+    /// Calculates MSD for all rates (0.7x to 2.0x) from an already-loaded
+    /// ROX chart, without blocking the calling task
+    async fn calculate_all_rates_from_rox_chart_async(chart: RoxChart) -> MinaCalcResult<AllRates>;
+}
+
+impl AsyncCalcExt for Calc {
+    async fn calculate_ssr_from_file_async(
+        path: PathBuf,
+        music_rate: f32,
+        score_goal: f32,
+        chart_rate: Option<f32>,
+    ) -> MinaCalcResult<SkillsetScores> {
+        run_blocking(move |calc| {
+            calc.calculate_ssr_from_file(&path, music_rate, score_goal, chart_rate)
+        })
+        .await
+    }
+
+    async fn calculate_all_rates_from_file_async(path: PathBuf) -> MinaCalcResult<AllRates> {
+        run_blocking(move |calc| calc.calculate_all_rates_from_file(&path)).await
+    }
+
+    async fn calculate_all_rates_from_rox_chart_async(chart: RoxChart) -> MinaCalcResult<AllRates> {
+        run_blocking(move |calc| calc.calculate_all_rates_from_rox_chart(&chart)).await
+    }
+}
+
+/// Runs `work` against a [`Calc`] borrowed from the global pool on
+/// `spawn_blocking`, returning the result through a oneshot so the caller's
+/// task isn't blocked while the synchronous FFI call runs
+async fn run_blocking<F, T>(work: F) -> MinaCalcResult<T>
+where
+    F: FnOnce(&Calc) -> MinaCalcResult<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+
+    tokio::task::spawn_blocking(move || {
+        let result = ThreadSafeCalcPool::get_global_calc().and_then(|calc| {
+            let result = work(&calc);
+            ThreadSafeCalcPool::return_global_calc(calc);
+            result
+        });
+        let _ = tx.send(result);
+    });
+
+    rx.await.unwrap_or_else(|_| {
+        Err(MinaCalcError::InternalError(
+            "async calculation task panicked".to_string(),
+        ))
+    })
+}
@@ -0,0 +1,135 @@
+//! Parallel batch scoring over a directory of charts
+//!
+//! Farms `auto_decode` + `calculate_all_rates_from_rox_chart` work out to a
+//! pool of worker threads, each owning its own [`Calc`] handle since the FFI
+//! handle isn't obviously shareable across threads. One bad file produces an
+//! `Err` entry rather than aborting the whole run.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crossbeam_channel::bounded;
+
+use crate::error::{MinaCalcError, MinaCalcResult};
+use crate::rox::RoxCalcExt;
+use crate::{wrapper::AllRates, Calc};
+
+/// File extensions `rhythm_open_exchange::auto_decode` can handle
+const CHART_EXTENSIONS: &[&str] = &["osu", "sm", "ssc", "rox"];
+
+/// Options controlling a directory batch scan
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// Number of worker threads to farm decoding + scoring out to. `None`
+    /// uses the available parallelism.
+    pub worker_count: Option<usize>,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions { worker_count: None }
+    }
+}
+
+/// Recursively scores every chart `auto_decode` can parse under `root`,
+/// dispatching work to `opts.worker_count` threads (each with its own
+/// [`Calc`] instance). `on_progress(completed, total)` is invoked on the
+/// calling thread once per finished item, in completion order, so callers
+/// can drive a progress bar for large song libraries.
+pub fn calculate_all_rates_from_dir(
+    root: &Path,
+    opts: BatchOptions,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Vec<(PathBuf, MinaCalcResult<AllRates>)> {
+    let paths = discover_chart_files(root);
+    let total = paths.len();
+
+    let worker_count = opts.worker_count.unwrap_or_else(|| {
+        thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    }).max(1);
+
+    // Bounded channel of pending paths feeds the worker pool; an unbounded
+    // results channel lets workers push back as soon as they finish without
+    // waiting on the consumer
+    let (path_tx, path_rx) = bounded::<PathBuf>(worker_count * 2);
+    let (result_tx, result_rx) = crossbeam_channel::unbounded::<(PathBuf, MinaCalcResult<AllRates>)>();
+
+    thread::scope(|scope| {
+        for _ in 0..worker_count {
+            let path_rx = path_rx.clone();
+            let result_tx = result_tx.clone();
+
+            scope.spawn(move || {
+                let calc = match Calc::new() {
+                    Ok(calc) => calc,
+                    Err(_) => {
+                        // No calculator for this worker: drain its share of
+                        // the queue with a consistent error rather than
+                        // leaving those paths unreported
+                        for path in path_rx.iter() {
+                            let _ = result_tx.send((path, Err(MinaCalcError::CalculatorCreationFailed)));
+                        }
+                        return;
+                    }
+                };
+
+                for path in path_rx.iter() {
+                    let result = calc.calculate_all_rates_from_file(&path);
+                    let _ = result_tx.send((path, result));
+                }
+            });
+        }
+        drop(result_tx);
+
+        scope.spawn(move || {
+            for path in paths {
+                if path_tx.send(path).is_err() {
+                    break;
+                }
+            }
+        });
+
+        // Drained on the calling thread, concurrently with the workers
+        // above, so `on_progress` fires as each chart finishes rather than
+        // only once the whole scan is done
+        let mut completed = 0;
+        let mut results = Vec::with_capacity(total);
+        for entry in result_rx.iter() {
+            completed += 1;
+            on_progress(completed, total);
+            results.push(entry);
+        }
+
+        results
+    })
+}
+
+/// Recursively walks `root`, returning every file whose extension
+/// `auto_decode` understands
+fn discover_chart_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| CHART_EXTENSIONS.iter().any(|known| known.eq_ignore_ascii_case(ext)))
+                .unwrap_or(false)
+            {
+                files.push(path);
+            }
+        }
+    }
+
+    files
+}
@@ -0,0 +1,173 @@
+//! Versioned on-disk cache for computed `AllRates`
+//!
+//! Keys each record by a fingerprint of the chart's sorted note rows
+//! (times + column bitflags) plus keycount, so an unchanged chart never
+//! gets rescored twice. Every record also carries the cache format version
+//! and the [`Calc::version`] that produced it, so a calculator upgrade or a
+//! layout change invalidates stale entries instead of handing back wrong
+//! numbers.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use rhythm_open_exchange::codec::auto_decode;
+
+use crate::error::{MinaCalcResult, RoxError};
+use crate::rox::RoxCalcExt;
+use crate::{wrapper::AllRates, wrapper::SkillsetScores, Calc, Note};
+
+/// Bumped whenever the on-disk record layout changes; unrelated to
+/// `Calc::version()`, which tracks the MinaCalc library itself
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+const RATE_COUNT: usize = 14;
+const SKILLSETS_PER_RATE: usize = 8;
+const HEADER_LEN: usize = 4 + 4 + 4;
+const RECORD_LEN: usize = HEADER_LEN + RATE_COUNT * SKILLSETS_PER_RATE * 4;
+
+/// Extension trait for Calc adding a file-backed cache over `AllRates`
+pub trait CachedCalcExt {
+    /// Calculates MSD for all rates from a chart file, reusing a cached
+    /// result under `cache_dir` when one matches the chart's fingerprint,
+    /// cache format, and calculator version
+    fn calculate_all_rates_cached<P: AsRef<Path>>(
+        &self,
+        path: P,
+        cache_dir: &Path,
+    ) -> MinaCalcResult<AllRates>;
+}
+
+impl CachedCalcExt for Calc {
+    fn calculate_all_rates_cached<P: AsRef<Path>>(
+        &self,
+        path: P,
+        cache_dir: &Path,
+    ) -> MinaCalcResult<AllRates> {
+        let path = path.as_ref();
+
+        let chart = auto_decode(path)
+            .map_err(|e| RoxError::DecodeFailed(format!("Failed to decode {:?}: {}", path, e)))?;
+
+        let notes = Self::chart_to_notes(&chart, None)?;
+        let keycount = chart.key_count as u32;
+
+        let cache_path = cache_entry_path(cache_dir, &notes, keycount);
+
+        if let Some(cached) = read_cache_entry(&cache_path, keycount) {
+            return Ok(cached);
+        }
+
+        let all_rates = self.calculate_all_rates_from_rox_chart(&chart)?;
+        write_cache_entry(&cache_path, keycount, &all_rates);
+
+        Ok(all_rates)
+    }
+}
+
+/// Hashes the sorted `(row_time, notes)` pairs plus keycount into a stable
+/// key that changes whenever the chart's actual content changes
+fn fingerprint_notes(notes: &[Note], keycount: u32) -> u64 {
+    let mut sorted: Vec<&Note> = notes.iter().collect();
+    sorted.sort_by(|a, b| a.row_time.partial_cmp(&b.row_time).unwrap());
+
+    let mut hasher = DefaultHasher::new();
+    keycount.hash(&mut hasher);
+    for note in sorted {
+        note.row_time.to_bits().hash(&mut hasher);
+        note.notes.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn cache_entry_path(cache_dir: &Path, notes: &[Note], keycount: u32) -> PathBuf {
+    let fingerprint = fingerprint_notes(notes, keycount);
+    cache_dir.join(format!("{:016x}.msdcache", fingerprint))
+}
+
+/// Reads and validates a cache record, returning `None` on anything that
+/// doesn't cleanly match (missing file, wrong length, stale format or
+/// calculator version, keycount mismatch) so the caller falls back to
+/// recomputing rather than trusting a corrupt or outdated entry
+fn read_cache_entry(cache_path: &Path, keycount: u32) -> Option<AllRates> {
+    let bytes = fs::read(cache_path).ok()?;
+    if bytes.len() != RECORD_LEN {
+        return None;
+    }
+
+    let format_version = u32::from_le_bytes(bytes[0..4].try_into().ok()?);
+    let calc_version = i32::from_le_bytes(bytes[4..8].try_into().ok()?);
+    let record_keycount = u32::from_le_bytes(bytes[8..12].try_into().ok()?);
+
+    if format_version != CACHE_FORMAT_VERSION
+        || calc_version != Calc::version()
+        || record_keycount != keycount
+    {
+        return None;
+    }
+
+    let mut msds = [SkillsetScores {
+        overall: 0.0,
+        stream: 0.0,
+        jumpstream: 0.0,
+        handstream: 0.0,
+        stamina: 0.0,
+        jackspeed: 0.0,
+        chordjack: 0.0,
+        technical: 0.0,
+    }; RATE_COUNT];
+
+    for (i, scores) in msds.iter_mut().enumerate() {
+        let offset = HEADER_LEN + i * SKILLSETS_PER_RATE * 4;
+        let mut values = [0.0f32; SKILLSETS_PER_RATE];
+        for (j, value) in values.iter_mut().enumerate() {
+            let start = offset + j * 4;
+            *value = f32::from_le_bytes(bytes[start..start + 4].try_into().ok()?);
+        }
+        *scores = SkillsetScores {
+            overall: values[0],
+            stream: values[1],
+            jumpstream: values[2],
+            handstream: values[3],
+            stamina: values[4],
+            jackspeed: values[5],
+            chordjack: values[6],
+            technical: values[7],
+        };
+    }
+
+    Some(AllRates { msds })
+}
+
+/// Writes a cache record, creating `cache_dir` if needed. Write failures are
+/// swallowed: a missing cache entry just means the next call recomputes.
+fn write_cache_entry(cache_path: &Path, keycount: u32, all_rates: &AllRates) {
+    if let Some(parent) = cache_path.parent() {
+        if fs::create_dir_all(parent).is_err() {
+            return;
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(RECORD_LEN);
+    bytes.extend_from_slice(&CACHE_FORMAT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&Calc::version().to_le_bytes());
+    bytes.extend_from_slice(&keycount.to_le_bytes());
+
+    for scores in &all_rates.msds {
+        for value in [
+            scores.overall,
+            scores.stream,
+            scores.jumpstream,
+            scores.handstream,
+            scores.stamina,
+            scores.jackspeed,
+            scores.chordjack,
+            scores.technical,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    let _ = fs::write(cache_path, bytes);
+}
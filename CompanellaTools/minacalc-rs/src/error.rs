@@ -41,6 +41,10 @@ pub enum RoxError {
     InvalidNote(String),
     /// Unsupported key count
     UnsupportedKeyCount(usize),
+    /// Failed to write a converted chart to disk
+    ExportFailed(String),
+    /// Failed to parse a `.ssq`-shaped step chart or its sidecar catalog
+    SsqParseFailed(String),
 }
 
 impl fmt::Display for MinaCalcError {
@@ -82,6 +86,8 @@ impl fmt::Display for RoxError {
             RoxError::NoNotes => write!(f, "No notes found in chart"),
             RoxError::InvalidNote(msg) => write!(f, "Invalid note: {}", msg),
             RoxError::UnsupportedKeyCount(count) => write!(f, "Unsupported key count: {}", count),
+            RoxError::ExportFailed(msg) => write!(f, "Failed to export chart: {}", msg),
+            RoxError::SsqParseFailed(msg) => write!(f, "Failed to parse .ssq-shaped chart: {}", msg),
         }
     }
 }
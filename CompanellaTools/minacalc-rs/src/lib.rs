@@ -7,6 +7,14 @@
 //! - `hashmap` (default): Provides HashMap conversion for MSD results
 //! - `thread`: Provides thread-safe calculator pool
 //! - `rox`: Provides universal rhythm game chart parsing (osu!, StepMania, etc.)
+//! - `batch`: Parallel directory scoring with a worker pool and progress reporting
+//! - `cache`: Versioned on-disk cache for computed `AllRates`, keyed by chart fingerprint
+//! - `async`: Futures-returning calculation API that offloads FFI work onto a blocking pool
+//! - `library`: Streaming batch scoring over a song library using the `thread` pool
+//!
+//! A per-interval difficulty timeline (`rox::timeline`) is blocked on a
+//! `calc_debug_intervals`/`free_intervals` pair landing on the C side; see
+//! `src/rox/mod.rs`.
 
 mod error;
 mod wrapper;
@@ -28,6 +36,18 @@ pub mod thread;
 #[cfg(feature = "rox")]
 pub mod rox;
 
+#[cfg(feature = "batch")]
+pub mod batch;
+
+#[cfg(feature = "cache")]
+pub mod cache;
+
+#[cfg(feature = "async")]
+pub mod async_calc;
+
+#[cfg(feature = "library")]
+pub mod library;
+
 #[cfg(feature = "utils")]
 pub mod utils;
 
@@ -41,5 +61,17 @@ pub use thread::*;
 #[cfg(feature = "rox")]
 pub use rox::*;
 
+#[cfg(feature = "batch")]
+pub use batch::*;
+
+#[cfg(feature = "cache")]
+pub use cache::*;
+
+#[cfg(feature = "async")]
+pub use async_calc::*;
+
+#[cfg(feature = "library")]
+pub use library::*;
+
 #[cfg(feature = "utils")]
 pub use utils::*;
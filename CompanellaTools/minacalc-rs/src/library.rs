@@ -0,0 +1,169 @@
+//! Streaming batch scoring over a song library
+//!
+//! Unlike [`batch::calculate_all_rates_from_dir`](crate::batch), which
+//! collects every result before returning, [`score_library`] hands back the
+//! receiving end of a channel immediately: workers (drawn from the
+//! [`thread`](crate::thread) feature's calculator pool) stream results in as
+//! each chart finishes, so a caller can start acting on the first songs in
+//! a multi-thousand-song library without waiting on the rest. One bad file
+//! produces a `Failed` outcome rather than aborting the scan, and charts
+//! over `max_notes` are flagged `Skipped` instead of scored.
+
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::{fs, io};
+
+use crossbeam_channel::{bounded, Receiver};
+
+use crate::rox::RoxCalcExt;
+use crate::thread::ThreadSafeCalcPool;
+use crate::wrapper::AllRates;
+
+/// File extensions `rhythm_open_exchange::auto_decode` can handle
+const CHART_EXTENSIONS: &[&str] = &["osu", "sm", "ssc", "rox"];
+
+/// Options controlling a [`score_library`] scan
+#[derive(Debug, Clone)]
+pub struct LibraryScanOptions {
+    /// Number of worker threads pulling from the calculator pool. `None`
+    /// uses the available parallelism.
+    pub worker_count: Option<usize>,
+    /// Charts with more rows than this are reported as `Skipped` instead of
+    /// scored. `None` disables the check.
+    pub max_notes: Option<usize>,
+}
+
+impl Default for LibraryScanOptions {
+    fn default() -> Self {
+        LibraryScanOptions {
+            worker_count: None,
+            max_notes: None,
+        }
+    }
+}
+
+/// What happened when scoring one file
+#[derive(Debug, Clone)]
+pub enum LibraryScoreOutcome {
+    /// Scored successfully
+    Scored(AllRates),
+    /// Skipped for exceeding `max_notes`
+    Skipped { note_count: usize, threshold: usize },
+    /// Decoding or scoring failed; the chart is reported, not the whole scan
+    Failed(String),
+}
+
+/// One file's result, sent as soon as it's ready
+#[derive(Debug, Clone)]
+pub struct LibraryScoreResult {
+    pub path: PathBuf,
+    pub outcome: LibraryScoreOutcome,
+}
+
+/// Recursively discovers every chart under `root` and scores it across a
+/// worker pool drawn from [`ThreadSafeCalcPool`], streaming results back
+/// through the returned channel as each one completes
+pub fn score_library(root: &Path, options: LibraryScanOptions) -> Receiver<LibraryScoreResult> {
+    let paths = discover_chart_files(root);
+
+    let worker_count = options
+        .worker_count
+        .unwrap_or_else(|| thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+        .max(1);
+    let max_notes = options.max_notes;
+
+    let (path_tx, path_rx) = bounded::<PathBuf>(worker_count * 2);
+    let (result_tx, result_rx) = bounded::<LibraryScoreResult>(worker_count * 2);
+
+    for _ in 0..worker_count {
+        let path_rx = path_rx.clone();
+        let result_tx = result_tx.clone();
+
+        thread::spawn(move || {
+            for path in path_rx.iter() {
+                let outcome = score_one(&path, max_notes);
+                if result_tx.send(LibraryScoreResult { path, outcome }).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    drop(result_tx);
+
+    thread::spawn(move || {
+        for path in paths {
+            if path_tx.send(path).is_err() {
+                break;
+            }
+        }
+    });
+
+    result_rx
+}
+
+/// Decodes and scores a single chart, isolating any failure to this file
+fn score_one(path: &Path, max_notes: Option<usize>) -> LibraryScoreOutcome {
+    let chart = match rhythm_open_exchange::codec::auto_decode(path) {
+        Ok(chart) => chart,
+        Err(e) => return LibraryScoreOutcome::Failed(format!("Failed to decode {:?}: {}", path, e)),
+    };
+
+    let notes = match crate::Calc::chart_to_notes(&chart, None) {
+        Ok(notes) => notes,
+        Err(e) => return LibraryScoreOutcome::Failed(e.to_string()),
+    };
+
+    if let Some(threshold) = max_notes {
+        if notes.len() > threshold {
+            return LibraryScoreOutcome::Skipped {
+                note_count: notes.len(),
+                threshold,
+            };
+        }
+    }
+
+    let keycount = chart.key_count as u32;
+
+    let calc = match ThreadSafeCalcPool::get_global_calc() {
+        Ok(calc) => calc,
+        Err(e) => return LibraryScoreOutcome::Failed(e.to_string()),
+    };
+
+    let result = calc.calc_msd_with_keycount(&notes, keycount);
+    ThreadSafeCalcPool::return_global_calc(calc);
+
+    match result {
+        Ok(all_rates) => LibraryScoreOutcome::Scored(all_rates),
+        Err(e) => LibraryScoreOutcome::Failed(e.to_string()),
+    }
+}
+
+/// Recursively walks `root`, returning every file whose extension
+/// `auto_decode` understands
+fn discover_chart_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let entries: io::Result<fs::ReadDir> = fs::read_dir(&dir);
+        let Ok(entries) = entries else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| CHART_EXTENSIONS.iter().any(|known| known.eq_ignore_ascii_case(ext)))
+                .unwrap_or(false)
+            {
+                files.push(path);
+            }
+        }
+    }
+
+    files
+}
@@ -0,0 +1,191 @@
+//! Exports a parsed ROX chart back out to osu!mania or StepMania
+//!
+//! This is the inverse of `auto_decode`: instead of reading a native format
+//! into the crate's common chart representation, [`RoxChartExt`] serializes
+//! that representation back out as text another tool (or MinaCalc's own
+//! osu!/StepMania parsers) can read. The `rhythm_open_exchange::RoxChart`
+//! this crate depends on only carries timed notes and a keycount, not
+//! title/artist/offset metadata, so exports fall back to placeholder
+//! metadata fields rather than inventing values; callers get the mismatch
+//! back as a warning instead of a silently wrong file.
+
+use std::fs;
+use std::path::Path;
+
+use rhythm_open_exchange::RoxChart;
+
+use crate::error::{RoxError, RoxResult};
+
+/// Rows per measure used when quantizing notes onto a StepMania beat grid
+const SM_ROWS_PER_MEASURE: usize = 192;
+
+/// Target format for [`convert_file`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// osu!mania `.osu` beatmap
+    Osu,
+    /// StepMania `.sm` song
+    Sm,
+}
+
+/// Extension trait adding export methods to `RoxChart`, the reverse
+/// direction of `auto_decode`
+pub trait RoxChartExt {
+    /// Serializes the chart as an osu!mania beatmap. Returns the file
+    /// contents plus any warnings about information that couldn't be
+    /// represented.
+    fn to_osu(&self) -> RoxResult<(String, Vec<String>)>;
+
+    /// Serializes the chart as a StepMania song with a single `#NOTES`
+    /// section. Returns the file contents plus any warnings about
+    /// information that couldn't be represented.
+    fn to_sm(&self) -> RoxResult<(String, Vec<String>)>;
+}
+
+impl RoxChartExt for RoxChart {
+    fn to_osu(&self) -> RoxResult<(String, Vec<String>)> {
+        let keycount = self.key_count as u32;
+        if keycount == 0 {
+            return Err(RoxError::UnsupportedKeyCount(self.key_count));
+        }
+
+        let mut warnings = Vec::new();
+        if keycount > 18 {
+            return Err(RoxError::UnsupportedKeyCount(self.key_count));
+        }
+
+        let mut out = String::new();
+        out.push_str("osu file format v14\n\n");
+        out.push_str("[General]\nAudioFilename: audio.mp3\nMode: 3\n\n");
+        out.push_str(
+            "[Metadata]\nTitle:Unknown\nArtist:Unknown\nCreator:rox-convert\nVersion:Converted\n\n",
+        );
+        out.push_str(&format!("[Difficulty]\nCircleSize:{}\n\n", keycount));
+        warnings.push(
+            "title/artist/offset metadata isn't carried by RoxChart; exported with placeholders"
+                .to_string(),
+        );
+
+        out.push_str("[HitObjects]\n");
+        for note in &self.notes {
+            if note.time_us < 0 {
+                return Err(RoxError::InvalidNote(
+                    "note has negative time".to_string(),
+                ));
+            }
+            let time_ms = note.time_us / 1000;
+            let column_x = column_to_osu_x(note.column, keycount);
+            out.push_str(&format!("{},192,{},1,0,0:0:0:0:\n", column_x, time_ms));
+        }
+
+        Ok((out, warnings))
+    }
+
+    fn to_sm(&self) -> RoxResult<(String, Vec<String>)> {
+        // The StepMania format places notes on a beat grid; without a BPM
+        // field to anchor that grid to, assume a fixed 60 BPM (1 beat per
+        // second) starting at the song's t=0, which keeps note ordering and
+        // relative spacing correct but won't match a BPM a player would
+        // recognize
+        const ASSUMED_BPM: f64 = 60.0;
+
+        if self.key_count != 4 {
+            return Err(RoxError::UnsupportedKeyCount(self.key_count));
+        }
+
+        let mut warnings = vec![
+            "title/artist/offset metadata isn't carried by RoxChart; exported with placeholders"
+                .to_string(),
+            format!(
+                "RoxChart has no BPM field; notes were placed on a grid assuming a fixed {} BPM",
+                ASSUMED_BPM
+            ),
+        ];
+
+        let mut measures: Vec<[u32; SM_ROWS_PER_MEASURE]> = vec![[0; SM_ROWS_PER_MEASURE]];
+
+        for note in &self.notes {
+            if note.time_us < 0 {
+                return Err(RoxError::InvalidNote(
+                    "note has negative time".to_string(),
+                ));
+            }
+
+            let time_seconds = note.time_us as f64 / 1_000_000.0;
+            let beat = time_seconds * (ASSUMED_BPM / 60.0);
+            let measure_index = (beat / 4.0).floor() as usize;
+            let row_in_measure =
+                ((beat - measure_index as f64 * 4.0) / 4.0 * SM_ROWS_PER_MEASURE as f64).round()
+                    as usize;
+            let row_in_measure = row_in_measure.min(SM_ROWS_PER_MEASURE - 1);
+
+            while measures.len() <= measure_index {
+                measures.push([0; SM_ROWS_PER_MEASURE]);
+            }
+
+            measures[measure_index][row_in_measure] |= 1u32 << note.column;
+        }
+
+        if measures.iter().all(|measure| measure.iter().all(|&row| row == 0)) {
+            return Err(RoxError::NoNotes);
+        }
+
+        let mut out = String::new();
+        out.push_str("#TITLE:Unknown;\n#ARTIST:Unknown;\n#OFFSET:0.000000;\n");
+        out.push_str(&format!("#BPMS:0.000000={:.6};\n", ASSUMED_BPM));
+        out.push_str("#STOPS:;\n\n");
+        out.push_str(
+            "#NOTES:\n     dance-single:\n     rox-convert:\n     Hard:\n     1:\n     0,0,0,0,0:\n",
+        );
+
+        let measure_strs: Vec<String> = measures
+            .iter()
+            .map(|measure| {
+                measure
+                    .iter()
+                    .map(|&row| format!("{:04b}", row).chars().rev().collect::<String>())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect();
+        out.push_str(&measure_strs.join("\n,\n"));
+        out.push_str("\n;\n");
+
+        if measures.len() > 1 {
+            warnings.push(format!(
+                "chart spans {} measures at the assumed tempo; verify timing before play",
+                measures.len()
+            ));
+        }
+
+        Ok((out, warnings))
+    }
+}
+
+/// Maps a 0-indexed column to the osu!mania hit object X coordinate for a
+/// given keycount, evenly dividing the 512-wide playfield
+fn column_to_osu_x(column: usize, keycount: u32) -> u32 {
+    (((column as f64 + 0.5) * 512.0) / keycount as f64).floor() as u32
+}
+
+/// Decodes `src` with `auto_decode` and writes it back out as `dst_format`
+/// at `dst`, returning any warnings about information the target format
+/// couldn't represent
+pub fn convert_file(
+    src: &Path,
+    dst: &Path,
+    dst_format: ExportFormat,
+) -> RoxResult<Vec<String>> {
+    let chart = rhythm_open_exchange::codec::auto_decode(src)
+        .map_err(|e| RoxError::DecodeFailed(format!("Failed to decode {:?}: {}", src, e)))?;
+
+    let (contents, warnings) = match dst_format {
+        ExportFormat::Osu => chart.to_osu()?,
+        ExportFormat::Sm => chart.to_sm()?,
+    };
+
+    fs::write(dst, contents)
+        .map_err(|e| RoxError::ExportFailed(format!("Failed to write {:?}: {}", dst, e)))?;
+
+    Ok(warnings)
+}
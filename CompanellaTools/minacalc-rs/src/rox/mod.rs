@@ -1,15 +1,36 @@
 use rhythm_open_exchange::{codec::auto_decode, RoxChart};
-use std::collections::HashMap;
 use std::path::Path;
 
 use crate::error::{MinaCalcResult, RoxError, RoxResult};
 use crate::{wrapper::AllRates, Calc, Note};
 
+pub mod convert;
+pub mod rate_solver;
+pub mod ssq;
+
+// No `timeline` module: a per-interval difficulty timeline needs a
+// `calc_debug_intervals`/`free_intervals` pair on the C side, which doesn't
+// exist. Blocked on that C implementation landing rather than shipped as
+// dead, unlinkable Rust.
+
+/// Default row-quantization tolerance: notes within this many milliseconds
+/// of a row's anchor are merged into that row instead of starting a new one
+const DEFAULT_ROW_TOLERANCE_MS: f64 = 2.5;
+
 /// Extension trait for Calc to handle universal rhythm game chart operations
 pub trait RoxCalcExt {
-    /// Converts ROX chart to MinaCalc notes with optional rate
+    /// Converts ROX chart to MinaCalc notes with optional rate, merging
+    /// notes within [`DEFAULT_ROW_TOLERANCE_MS`] of each other into one row
     fn chart_to_notes(chart: &RoxChart, rate: Option<f32>) -> RoxResult<Vec<Note>>;
 
+    /// Converts ROX chart to MinaCalc notes with optional rate, merging
+    /// notes within `tolerance_ms` of each other into one row
+    fn chart_to_notes_with_tolerance(
+        chart: &RoxChart,
+        rate: Option<f32>,
+        tolerance_ms: f64,
+    ) -> RoxResult<Vec<Note>>;
+
     /// Calculates SSR (single rate) from any supported rhythm game file
     fn calculate_ssr_from_file<P: AsRef<Path>>(
         &self,
@@ -41,49 +62,61 @@ pub trait RoxCalcExt {
 impl RoxCalcExt for Calc {
     /// Converts ROX chart to MinaCalc notes with optional rate
     fn chart_to_notes(chart: &RoxChart, rate: Option<f32>) -> RoxResult<Vec<Note>> {
+        Self::chart_to_notes_with_tolerance(chart, rate, DEFAULT_ROW_TOLERANCE_MS)
+    }
+
+    fn chart_to_notes_with_tolerance(
+        chart: &RoxChart,
+        rate: Option<f32>,
+        tolerance_ms: f64,
+    ) -> RoxResult<Vec<Note>> {
         let rate = rate.unwrap_or(1.0);
 
         if rate <= 0.0 {
             return Err(RoxError::InvalidRate(rate));
         }
 
-        // Use HashMap to merge notes at the same time
-        let mut time_notes: HashMap<u64, u32> = HashMap::new();
-
-        // Convert ROX notes to MinaCalc format
-        for note in &chart.notes {
-            // ROX uses microseconds, convert to seconds then apply rate
-            let time_seconds = (note.time_us as f64 / 1_000_000.0) / rate as f64;
-
-            // Convert back to microseconds for HashMap key (to preserve precision)
-            let time_key = (time_seconds * 1_000_000.0) as u64;
-
-            // Get column index and convert to bitflag
-            // Column 0 = 0b0001, Column 1 = 0b0010, Column 2 = 0b0100, Column 3 = 0b1000
-            let column_bitflag = 1u32 << note.column;
-
-            // Merge bitflags for notes at the same time using OR operation
-            time_notes
-                .entry(time_key)
-                .and_modify(|existing_notes| *existing_notes |= column_bitflag)
-                .or_insert(column_bitflag);
-        }
+        // Collect (time_seconds, column_bitflag) for every note, applying rate
+        let mut timed_notes: Vec<(f64, u32)> = chart
+            .notes
+            .iter()
+            .map(|note| {
+                let time_seconds = (note.time_us as f64 / 1_000_000.0) / rate as f64;
+                let column_bitflag = 1u32 << note.column;
+                (time_seconds, column_bitflag)
+            })
+            .collect();
 
-        if time_notes.is_empty() {
+        if timed_notes.is_empty() {
             return Err(RoxError::NoNotes);
         }
 
-        // Convert HashMap back to sorted Vec<Note>
-        let mut notes: Vec<Note> = time_notes
-            .into_iter()
-            .map(|(time_key, notes)| Note {
-                notes,
-                row_time: (time_key as f64 / 1_000_000.0) as f32,
-            })
-            .collect();
-
-        // Sort by time
-        notes.sort_by(|a, b| a.row_time.partial_cmp(&b.row_time).unwrap());
+        // Sort by time, then sweep left to right merging notes within
+        // `tolerance_ms` of the current row's anchor (its first note) into
+        // that row rather than starting a new one
+        timed_notes.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let tolerance_seconds = tolerance_ms / 1000.0;
+
+        let mut notes = Vec::new();
+        let mut anchor_time = timed_notes[0].0;
+        let mut row_columns = timed_notes[0].1;
+
+        for &(time_seconds, column_bitflag) in &timed_notes[1..] {
+            if time_seconds - anchor_time < tolerance_seconds {
+                row_columns |= column_bitflag;
+            } else {
+                notes.push(Note {
+                    notes: row_columns,
+                    row_time: anchor_time as f32,
+                });
+                anchor_time = time_seconds;
+                row_columns = column_bitflag;
+            }
+        }
+        notes.push(Note {
+            notes: row_columns,
+            row_time: anchor_time as f32,
+        });
 
         // Validate all notes
         Self::validate_notes(&notes)?;
@@ -182,7 +215,14 @@ impl RoxCalcExt for Calc {
     }
 }
 
-// Helper extension for Calc to support keycount parameter
+// Helper extension for Calc to support keycount parameter.
+//
+// `minacalc-rs-505` carries its own copy of this pair (in `osu.rs`) rather
+// than depending on this one: it's threaded through that crate's own
+// `Calc::handle` and bindgen-generated `NoteInfo`/`AllRates`/`SkillsetScores`,
+// binding a different build of the native MinaCalc library than this crate's.
+// The two can't delegate to each other without running the wrong native
+// calculator, so the duplication is intentional rather than drift.
 impl Calc {
     /// Calculates MSD with configurable keycount
     pub fn calc_msd_with_keycount(
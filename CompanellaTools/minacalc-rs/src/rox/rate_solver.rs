@@ -0,0 +1,133 @@
+//! Target-difficulty rate solver
+//!
+//! Works backwards from a desired difficulty value: "what rate makes this
+//! chart a 30 overall?" MSD/SSR is monotonically non-decreasing in rate, so
+//! [`RateSolverExt::find_rate_for_target`] bisects the rate interval instead
+//! of sweeping it.
+
+use rhythm_open_exchange::RoxChart;
+
+use crate::error::MinaCalcResult;
+use crate::rox::RoxCalcExt;
+use crate::wrapper::SkillsetScores;
+use crate::{Calc, Skillset};
+
+/// Options controlling [`RateSolverExt::find_rate_for_target`]'s bisection
+#[derive(Debug, Clone, Copy)]
+pub struct RateSolverOptions {
+    /// Lower bound of the searched rate interval
+    pub min_rate: f32,
+    /// Upper bound of the searched rate interval
+    pub max_rate: f32,
+    /// Score goal passed through to the underlying SSR calculation
+    pub score_goal: f32,
+    /// Bisection stops once the achieved value is within this of `target_msd`
+    pub tolerance: f32,
+    /// Hard cap on bisection steps, in case tolerance is never reached
+    pub max_iterations: u32,
+}
+
+impl Default for RateSolverOptions {
+    fn default() -> Self {
+        RateSolverOptions {
+            min_rate: 0.7,
+            max_rate: 2.0,
+            score_goal: 93.0,
+            tolerance: 0.01,
+            max_iterations: 20,
+        }
+    }
+}
+
+/// A solved rate and the SSR it produced
+#[derive(Debug, Clone, Copy)]
+pub struct RateSolverResult {
+    pub rate: f32,
+    pub achieved: SkillsetScores,
+}
+
+/// Extension trait adding a target-difficulty rate search to Calc
+pub trait RateSolverExt {
+    /// Searches `options.min_rate..=options.max_rate` for the rate whose
+    /// `skillset` value is closest to `target_msd`, evaluating
+    /// `calculate_ssr_from_rox_chart` at each bisection midpoint. Returns
+    /// `Ok(None)` when `target_msd` falls outside
+    /// `[value(min_rate), value(max_rate)]`, since no rate in range can
+    /// reach it.
+    fn find_rate_for_target(
+        &self,
+        chart: &RoxChart,
+        skillset: Skillset,
+        target_msd: f32,
+        options: RateSolverOptions,
+    ) -> MinaCalcResult<Option<RateSolverResult>>;
+}
+
+impl RateSolverExt for Calc {
+    fn find_rate_for_target(
+        &self,
+        chart: &RoxChart,
+        skillset: Skillset,
+        target_msd: f32,
+        options: RateSolverOptions,
+    ) -> MinaCalcResult<Option<RateSolverResult>> {
+        let mut lo = options.min_rate;
+        let mut hi = options.max_rate;
+
+        let scores_at = |rate: f32, calc: &Calc| -> MinaCalcResult<SkillsetScores> {
+            calc.calculate_ssr_from_rox_chart(chart, 1.0, options.score_goal, Some(rate))
+        };
+
+        let lo_scores = scores_at(lo, self)?;
+        let hi_scores = scores_at(hi, self)?;
+        let lo_value = skillset.get(&lo_scores);
+        let hi_value = skillset.get(&hi_scores);
+
+        if target_msd < lo_value {
+            return Ok(None);
+        }
+        if target_msd > hi_value {
+            return Ok(None);
+        }
+
+        let mut best = if (lo_value - target_msd).abs() <= (hi_value - target_msd).abs() {
+            RateSolverResult {
+                rate: lo,
+                achieved: lo_scores,
+            }
+        } else {
+            RateSolverResult {
+                rate: hi,
+                achieved: hi_scores,
+            }
+        };
+
+        for _ in 0..options.max_iterations {
+            let mid = lo + (hi - lo) / 2.0;
+            let mid_scores = scores_at(mid, self)?;
+            let mid_value = skillset.get(&mid_scores);
+
+            if (mid_value - target_msd).abs() < (skillset.get(&best.achieved) - target_msd).abs() {
+                best = RateSolverResult {
+                    rate: mid,
+                    achieved: mid_scores,
+                };
+            }
+
+            if (mid_value - target_msd).abs() <= options.tolerance {
+                return Ok(Some(RateSolverResult {
+                    rate: mid,
+                    achieved: mid_scores,
+                }));
+            }
+
+            if mid_value < target_msd {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Ok(Some(best))
+    }
+}
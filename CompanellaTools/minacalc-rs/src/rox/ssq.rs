@@ -0,0 +1,357 @@
+//! `.ssq`-shaped chunked step-chart import
+//!
+//! This does **not** read real Konami `.ssq`/`musicdb` files: actual arcade
+//! and console revisions use an undocumented binary layout that varies by
+//! cabinet generation, and reverse-engineering it is out of scope here.
+//! What this module reads is a placeholder chunked format this crate
+//! invented for the same shape of problem (a tag chunk carrying BPM
+//! changes, a step chunk per difficulty, optional sidecar metadata) so the
+//! DDR-style import path through `rox` can be built and exercised before a
+//! real byte-accurate reader lands. Concretely: a `u32` chunk count, chunks
+//! tagged by a `u32` kind + length, tag chunk entries as `(beat, bpm)`
+//! pairs, step chunk entries as `(tick, panel_bitmask, flags)` with ticks
+//! at [`TICKS_PER_BEAT`] resolution; malformed input is reported through
+//! [`RoxError::SsqParseFailed`] rather than silently misread. Row times are
+//! derived from the tempo chunk the same way `sm.rs::beat_to_seconds`
+//! derives them from `#BPMS`.
+//!
+//! This placeholder format carries no title/artist text, so metadata is
+//! optionally resolved from a sidecar catalog file by matching the chart's
+//! basename (see [`MusicDbArchive`]) — again this crate's own
+//! normalization, not Konami's real `musicdb`/`startup.arc` layout.
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::{MinaCalcResult, RoxError, RoxResult};
+use crate::{wrapper::AllRates, Calc, Note};
+
+/// A DDR panel layout: single-player (4 panels) or doubles (8 panels)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelLayout {
+    Single4,
+    Double8,
+}
+
+impl PanelLayout {
+    fn keycount(self) -> u32 {
+        match self {
+            PanelLayout::Single4 => 4,
+            PanelLayout::Double8 => 8,
+        }
+    }
+}
+
+const CHUNK_TAG_TEMPO: u32 = 1;
+const CHUNK_TAG_STEP: u32 = 2;
+
+/// Bit in a step chunk entry's flags byte marking a shock arrow
+const FLAG_SHOCK: u8 = 0x02;
+
+/// Step chunk tick resolution: ticks per beat (4 beats to a measure)
+const TICKS_PER_BEAT: f64 = 48.0;
+
+/// One `(beat, bpm)` tempo change, sorted ascending by `beat`
+type TempoChange = (f64, f64);
+
+/// A parsed `.ssq` step chart, ready for MinaCalc scoring
+pub struct SsqChart {
+    pub panel_layout: PanelLayout,
+    pub notes: Vec<Note>,
+}
+
+/// Parses raw `.ssq` bytes into timed MinaCalc notes
+pub fn parse_ssq(bytes: &[u8]) -> RoxResult<SsqChart> {
+    let mut cursor = 0usize;
+    let chunk_count = read_u32(bytes, &mut cursor)?;
+
+    let mut tempo: Vec<TempoChange> = Vec::new();
+    let mut step_entries: Vec<(u32, u16, u8)> = Vec::new();
+    let mut panel_layout = PanelLayout::Single4;
+
+    for _ in 0..chunk_count {
+        let tag = read_u32(bytes, &mut cursor)?;
+        let len = read_u32(bytes, &mut cursor)? as usize;
+        let end = cursor
+            .checked_add(len)
+            .filter(|&end| end <= bytes.len())
+            .ok_or_else(|| RoxError::SsqParseFailed("chunk length exceeds file size".to_string()))?;
+        let chunk = &bytes[cursor..end];
+
+        match tag {
+            CHUNK_TAG_TEMPO => tempo.extend(parse_tempo_chunk(chunk)?),
+            CHUNK_TAG_STEP => {
+                let (layout, entries) = parse_step_chunk(chunk)?;
+                panel_layout = layout;
+                step_entries.extend(entries);
+            }
+            _ => {
+                // Unknown chunk kinds (song preview data, lighting cues,
+                // etc.) don't affect note timing; skip rather than error
+            }
+        }
+
+        cursor = end;
+    }
+
+    if step_entries.is_empty() {
+        return Err(RoxError::NoNotes);
+    }
+    if tempo.is_empty() {
+        tempo.push((0.0, 120.0));
+    }
+    tempo.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let max_panels = 1u32 << panel_layout.keycount();
+
+    let mut notes = Vec::with_capacity(step_entries.len());
+    for (tick, panel_bitmask, flags) in step_entries {
+        let beat = tick as f64 / TICKS_PER_BEAT;
+        let row_time = beat_to_seconds(beat, &tempo) as f32;
+
+        if flags & FLAG_SHOCK != 0 {
+            // Shock arrows hit every panel at once; MinaCalc has no concept
+            // of them beyond "notes in this row", so OR every column in
+            continue_with_shock(panel_layout, row_time, &mut notes);
+            continue;
+        }
+
+        let panel_bitmask = panel_bitmask as u32;
+        if panel_bitmask >= max_panels {
+            return Err(RoxError::SsqParseFailed(format!(
+                "step entry panel bitmask {:#x} exceeds {}-panel layout",
+                panel_bitmask,
+                panel_layout.keycount()
+            )));
+        }
+
+        notes.push(Note {
+            notes: panel_bitmask,
+            row_time,
+        });
+    }
+
+    notes.sort_by(|a, b| a.row_time.partial_cmp(&b.row_time).unwrap());
+
+    Ok(SsqChart {
+        panel_layout,
+        notes,
+    })
+}
+
+fn continue_with_shock(panel_layout: PanelLayout, row_time: f32, notes: &mut Vec<Note>) {
+    let all_panels = (1u32 << panel_layout.keycount()) - 1;
+    notes.push(Note {
+        notes: all_panels,
+        row_time,
+    });
+}
+
+/// Converts a beat position to an absolute time in seconds by walking the
+/// tempo chunk's BPM segments, mirroring `sm.rs::beat_to_seconds` (`.ssq`
+/// charts have no `#OFFSET`/`#STOPS` equivalent, so there's nothing else
+/// to fold in)
+fn beat_to_seconds(beat: f64, tempo: &[TempoChange]) -> f64 {
+    let mut time = 0.0;
+    let mut prev_beat = 0.0;
+    let mut prev_bpm = tempo.first().map(|&(_, bpm)| bpm).unwrap_or(120.0);
+
+    for &(seg_beat, bpm) in tempo {
+        if seg_beat >= beat {
+            break;
+        }
+        time += (seg_beat - prev_beat) * 60.0 / prev_bpm;
+        prev_beat = seg_beat;
+        prev_bpm = bpm;
+    }
+    time += (beat - prev_beat) * 60.0 / prev_bpm;
+
+    time
+}
+
+/// Tempo chunk payload: repeated `(f64 beat, f64 bpm)` pairs
+fn parse_tempo_chunk(chunk: &[u8]) -> RoxResult<Vec<TempoChange>> {
+    if chunk.len() % 16 != 0 {
+        return Err(RoxError::SsqParseFailed(
+            "tempo chunk length isn't a multiple of the entry size".to_string(),
+        ));
+    }
+
+    let mut changes = Vec::with_capacity(chunk.len() / 16);
+    for entry in chunk.chunks_exact(16) {
+        let beat = f64::from_le_bytes(entry[0..8].try_into().unwrap());
+        let bpm = f64::from_le_bytes(entry[8..16].try_into().unwrap());
+        changes.push((beat, bpm));
+    }
+    Ok(changes)
+}
+
+/// Step chunk payload: `u8 panel_count`, `u8 _reserved`, then repeated
+/// `(u32 tick, u16 panel_bitmask, u8 flags, u8 _reserved)` entries, ticks
+/// at [`TICKS_PER_BEAT`] resolution
+fn parse_step_chunk(chunk: &[u8]) -> RoxResult<(PanelLayout, Vec<(u32, u16, u8)>)> {
+    if chunk.len() < 2 {
+        return Err(RoxError::SsqParseFailed("step chunk is too short".to_string()));
+    }
+
+    let panel_count = chunk[0];
+    let panel_layout = match panel_count {
+        4 => PanelLayout::Single4,
+        8 => PanelLayout::Double8,
+        other => {
+            return Err(RoxError::UnsupportedKeyCount(other as usize));
+        }
+    };
+
+    let body = &chunk[2..];
+    if body.len() % 8 != 0 {
+        return Err(RoxError::SsqParseFailed(
+            "step chunk body length isn't a multiple of the entry size".to_string(),
+        ));
+    }
+
+    let mut entries = Vec::with_capacity(body.len() / 8);
+    for entry in body.chunks_exact(8) {
+        let tick = u32::from_le_bytes(entry[0..4].try_into().unwrap());
+        let panel_bitmask = u16::from_le_bytes(entry[4..6].try_into().unwrap());
+        let flags = entry[6];
+        entries.push((tick, panel_bitmask, flags));
+    }
+
+    Ok((panel_layout, entries))
+}
+
+fn read_u32(bytes: &[u8], cursor: &mut usize) -> RoxResult<u32> {
+    let end = cursor
+        .checked_add(4)
+        .filter(|&end| end <= bytes.len())
+        .ok_or_else(|| RoxError::SsqParseFailed("unexpected end of file".to_string()))?;
+    let value = u32::from_le_bytes(bytes[*cursor..end].try_into().unwrap());
+    *cursor = end;
+    Ok(value)
+}
+
+/// Song metadata resolved from this crate's placeholder sidecar catalog
+#[derive(Debug, Clone)]
+pub struct SongMetadata {
+    pub title: String,
+    pub artist: String,
+    pub bpm_display: String,
+}
+
+/// A minimal catalog parsed from this crate's placeholder sidecar format:
+/// records are `basename\0title\0artist\0bpm_display\0`. This is *not* a
+/// reader for Konami's real `musicdb`/`startup.arc` archive, which uses an
+/// undocumented on-disk layout that varies by cabinet revision
+pub struct MusicDbArchive {
+    entries: Vec<(String, SongMetadata)>,
+}
+
+impl MusicDbArchive {
+    /// Loads and indexes an archive's catalog by chart basename
+    pub fn open(path: &Path) -> RoxResult<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| RoxError::SsqParseFailed(format!("failed to read {:?}: {}", path, e)))?;
+
+        let mut entries = Vec::new();
+        for record in contents.split('\0').collect::<Vec<_>>().chunks_exact(4) {
+            let [basename, title, artist, bpm_display] = record else {
+                continue;
+            };
+            if basename.is_empty() {
+                continue;
+            }
+            entries.push((
+                basename.to_string(),
+                SongMetadata {
+                    title: title.to_string(),
+                    artist: artist.to_string(),
+                    bpm_display: bpm_display.to_string(),
+                },
+            ));
+        }
+
+        Ok(MusicDbArchive { entries })
+    }
+
+    /// Looks up metadata by matching a chart's basename against the catalog
+    pub fn resolve(&self, basename: &str) -> Option<&SongMetadata> {
+        self.entries
+            .iter()
+            .find(|(entry_basename, _)| entry_basename == basename)
+            .map(|(_, metadata)| metadata)
+    }
+}
+
+/// Extension trait for Calc to handle this crate's placeholder `.ssq`-shaped
+/// step charts (see the module docs for why this isn't a real DDR reader)
+pub trait DdrCalcExt {
+    /// Calculates MSD for all rates from a `.ssq`-shaped file, optionally
+    /// resolving the chart's metadata from a sidecar catalog file
+    fn calculate_all_rates_from_ssq_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        archive_path: Option<&Path>,
+    ) -> MinaCalcResult<(AllRates, Option<SongMetadata>)>;
+
+    /// Calculates SSR (single rate) from a `.ssq`-shaped file, optionally
+    /// resolving the chart's metadata from a sidecar catalog file
+    fn calculate_ssr_from_ssq_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        music_rate: f32,
+        score_goal: f32,
+        archive_path: Option<&Path>,
+    ) -> MinaCalcResult<(crate::wrapper::SkillsetScores, Option<SongMetadata>)>;
+}
+
+/// Resolves a chart's metadata from an archive by matching its basename,
+/// if an archive path was given
+fn resolve_metadata(path: &Path, archive_path: Option<&Path>) -> RoxResult<Option<SongMetadata>> {
+    let Some(archive_path) = archive_path else {
+        return Ok(None);
+    };
+
+    let archive = MusicDbArchive::open(archive_path)?;
+    let basename = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    Ok(archive.resolve(basename).cloned())
+}
+
+impl DdrCalcExt for Calc {
+    fn calculate_all_rates_from_ssq_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        archive_path: Option<&Path>,
+    ) -> MinaCalcResult<(AllRates, Option<SongMetadata>)> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .map_err(|e| RoxError::SsqParseFailed(format!("failed to read {:?}: {}", path, e)))?;
+        let chart = parse_ssq(&bytes)?;
+        let metadata = resolve_metadata(path, archive_path)?;
+
+        let rates = self.calc_msd_with_keycount(&chart.notes, chart.panel_layout.keycount())?;
+        Ok((rates, metadata))
+    }
+
+    fn calculate_ssr_from_ssq_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        music_rate: f32,
+        score_goal: f32,
+        archive_path: Option<&Path>,
+    ) -> MinaCalcResult<(crate::wrapper::SkillsetScores, Option<SongMetadata>)> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .map_err(|e| RoxError::SsqParseFailed(format!("failed to read {:?}: {}", path, e)))?;
+        let chart = parse_ssq(&bytes)?;
+        let metadata = resolve_metadata(path, archive_path)?;
+
+        let scores = self.calc_ssr_with_keycount(
+            &chart.notes,
+            music_rate,
+            score_goal,
+            chart.panel_layout.keycount(),
+        )?;
+        Ok((scores, metadata))
+    }
+}
@@ -48,6 +48,69 @@ impl From<NoteInfo> for Note {
     }
 }
 
+/// One of MinaCalc's seven rated patterns (everything but `overall`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Skillset {
+    Stream,
+    Jumpstream,
+    Handstream,
+    Stamina,
+    Jackspeed,
+    Chordjack,
+    Technical,
+}
+
+impl Skillset {
+    /// All seven variants, in the crate's canonical order
+    pub fn all() -> impl Iterator<Item = Skillset> {
+        [
+            Skillset::Stream,
+            Skillset::Jumpstream,
+            Skillset::Handstream,
+            Skillset::Stamina,
+            Skillset::Jackspeed,
+            Skillset::Chordjack,
+            Skillset::Technical,
+        ]
+        .into_iter()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Skillset::Stream => "stream",
+            Skillset::Jumpstream => "jumpstream",
+            Skillset::Handstream => "handstream",
+            Skillset::Stamina => "stamina",
+            Skillset::Jackspeed => "jackspeed",
+            Skillset::Chordjack => "chordjack",
+            Skillset::Technical => "technical",
+        }
+    }
+
+    /// Reads the value of this skillset out of a score struct
+    pub fn get(&self, scores: &SkillsetScores) -> f32 {
+        match self {
+            Skillset::Stream => scores.stream,
+            Skillset::Jumpstream => scores.jumpstream,
+            Skillset::Handstream => scores.handstream,
+            Skillset::Stamina => scores.stamina,
+            Skillset::Jackspeed => scores.jackspeed,
+            Skillset::Chordjack => scores.chordjack,
+            Skillset::Technical => scores.technical,
+        }
+    }
+}
+
+impl std::str::FromStr for Skillset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Skillset::all()
+            .find(|skillset| skillset.as_str() == s)
+            .ok_or_else(|| format!("unknown skillset: {}", s))
+    }
+}
+
 /// Represents difficulty scores for different skillsets
 #[derive(Debug, Clone, Copy)]
 pub struct SkillsetScores {
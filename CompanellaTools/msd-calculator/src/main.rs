@@ -4,7 +4,7 @@
 //! MinaCalc Skill Difficulty (MSD) ratings in JSON format.
 
 use clap::Parser;
-use minacalc_rs::{Calc, RoxCalcExt, AllRates, SkillsetScores};
+use minacalc_rs::{Calc, RoxCalcExt, AllRates, SkillsetScores, Skillset};
 use rhythm_open_exchange::codec::auto_decode;
 use serde::{Serialize, Deserialize};
 use std::path::PathBuf;
@@ -135,20 +135,9 @@ fn is_valid_rate(rate: f32) -> bool {
 
 /// Get the dominant skillset name from scores (highest non-overall score)
 fn get_dominant_skillset(scores: &SkillsetScores) -> String {
-    let skillsets = [
-        ("stream", scores.stream),
-        ("jumpstream", scores.jumpstream),
-        ("handstream", scores.handstream),
-        ("stamina", scores.stamina),
-        ("jackspeed", scores.jackspeed),
-        ("chordjack", scores.chordjack),
-        ("technical", scores.technical),
-    ];
-    
-    skillsets
-        .iter()
-        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
-        .map(|(name, _)| name.to_string())
+    Skillset::all()
+        .max_by(|a, b| a.get(scores).partial_cmp(&b.get(scores)).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|skillset| skillset.as_str().to_string())
         .unwrap_or_else(|| "unknown".to_string())
 }
 